@@ -1,7 +1,7 @@
 """Python methods for interactive with a Pact Mock Service."""
 from .broker import Broker
 from .consumer import Consumer
-from .matchers import EachLike, Like, SomethingLike, Term, Format
+from .matchers import EachLike, Like, OneOf, SomethingLike, StringLength, Term, Format
 from .message_pact import MessagePact
 from .message_consumer import MessageConsumer
 from .pact import Pact
@@ -11,4 +11,5 @@ from .verifier import Verifier
 from .__version__ import __version__  # noqa: F401
 
 __all__ = ('Broker', 'Consumer', 'EachLike', 'Like', 'MessageConsumer', 'MessagePact',
-           'Pact', 'Provider', 'SomethingLike', 'Term', 'Format', 'Verifier')
+           'OneOf', 'Pact', 'Provider', 'SomethingLike', 'StringLength', 'Term',
+           'Format', 'Verifier')
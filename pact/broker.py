@@ -49,7 +49,7 @@ class Broker():
         return name.lower().replace(' ', '_')
 
     def publish(self, consumer_name, version, pact_dir=None,
-                tag_with_git_branch=None, consumer_tags=None):
+                tag_with_git_branch=None, consumer_tags=None, branch=None):
         """Publish the generated pact files to the specified pact broker."""
         if self.broker_base_url is None \
                 and "PACT_BROKER_BASE_URL" not in os.environ:
@@ -85,6 +85,9 @@ class Broker():
             for tag in consumer_tags:
                 command.extend(['-t', tag])
 
+        if branch:
+            command.append('--branch={}'.format(branch))
+
         print(f"PactBroker command: {command}")
 
         publish_process = Popen(command)
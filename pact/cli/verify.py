@@ -1,7 +1,7 @@
 """Methods to verify previously created pacts."""
 import sys
 
-from pact.verify_wrapper import path_exists, expand_directories, VerifyWrapper
+from pact.verify_wrapper import path_exists, expand_directories, VerifyWrapper, REPORT_FORMATS
 
 import click
 
@@ -63,6 +63,9 @@ import click
     multiple=True,
     help='Tag to apply to the provider application version. '
          'May be specified multiple times.')
+@click.option(
+    'provider_version_branch', '--provider-version-branch',
+    help='The name of the branch the provider version belongs to.')
 @click.option(
     'password', '--pact-broker-password',
     envvar='PACT_BROKER_PASSWORD',
@@ -100,6 +103,10 @@ import click
     default=False,
     help='Publish verification results to the broker.',
     is_flag=True)
+@click.option(
+    'build_url', '--build-url',
+    help='URL of the build that ran the verification, published with the '
+         'verification results.')
 @click.option(
     '--verbose/--no-verbose',
     default=False,
@@ -110,6 +117,14 @@ import click
 @click.option(
     'log_level', '--log-level',
     help='The logging level.')
+@click.option(
+    'report_format', '-f', '--format',
+    type=click.Choice(REPORT_FORMATS),
+    help='The verification report format. '
+         'Defaults to the human readable progress output.')
+@click.option(
+    'report_output', '-o', '--out',
+    help='Write the verification report to a file instead of stdout.')
 @click.option(
     'enable_pending', '--enable-pending',
     default=False,
@@ -123,9 +138,11 @@ import click
          'For more information, see https://docs.pact.io/pact_broker/advanced_topics/wip_pacts/',)
 def main(pacts, base_url, pact_url, pact_urls, states_url, states_setup_url,
          username, broker_base_url, consumer_version_tag, consumer_version_selector,
-         provider_version_tag, password, token, provider, headers, timeout,
-         provider_app_version, publish_verification_results, verbose, log_dir,
-         log_level, enable_pending, include_wip_pacts_since):
+         provider_version_tag, provider_version_branch, password, token,
+         provider, headers, timeout, provider_app_version,
+         publish_verification_results, build_url, verbose, log_dir,
+         log_level, report_format, report_output, enable_pending,
+         include_wip_pacts_since):
     """
     Verify one or more contracts against a provider service.
 
@@ -166,6 +183,9 @@ def main(pacts, base_url, pact_url, pact_urls, states_url, states_setup_url,
     if publish_verification_results:
         validate_publish(error, provider_app_version)
 
+    if provider_version_branch:
+        validate_branch(error, provider_app_version)
+
     options = {
         'broker_password': password,
         'broker_username': username,
@@ -173,14 +193,18 @@ def main(pacts, base_url, pact_url, pact_urls, states_url, states_setup_url,
         'broker_url': broker_base_url,
         'log_dir': log_dir,
         'log_level': log_level,
+        'report_format': report_format,
+        'report_output': report_output,
         'provider_app_version': provider_app_version,
         'custom_provider_headers': list(headers),
         'publish_verification_results': publish_verification_results,
+        'build_url': build_url,
         'timeout': timeout,
         'verbose': verbose,
         'consumer_tags': list(consumer_version_tag),
         'consumer_selectors': list(consumer_version_selector),
         'provider_tags': list(provider_version_tag),
+        'provider_version_branch': provider_version_branch,
         'provider_states_setup_url': states_setup_url,
     }
 
@@ -208,6 +232,17 @@ def validate_publish(error, provider_app_version):
         raise click.Abort()
 
 
+def validate_branch(error, provider_app_version):
+    """Provider version branch requires a provider version."""
+    if not provider_app_version:
+        click.echo(
+            error
+            + ' Provider application version is required '
+            + 'when --provider-version-branch is set'
+        )
+        raise click.Abort()
+
+
 def broker_not_provided(broker_base_url, provider):
     """Check if broker not provided."""
     return (broker_base_url == '' or provider == '')
@@ -1,6 +1,8 @@
 """Classes for defining request and response data that is variable."""
 import six
 import datetime
+import json
+import re
 
 from enum import Enum
 
@@ -179,12 +181,144 @@ class Term(Matcher):
                     's': self.matcher}}}
 
 
+class StringLength(Term):
+    """
+    Expect a string whose length is within the given bounds.
+
+    Example:
+    >>> from pact import Consumer, Provider
+    >>> pact = Consumer('consumer').has_pact_with(Provider('provider'))
+    >>> (pact.given('there is a user')
+    ...  .upon_receiving('a request for the user')
+    ...  .with_request('get', '/user')
+    ...  .will_respond_with(200, body={
+    ...    'id': StringLength(10, 36, 'abcdefghij')
+    ...  }))
+
+    Would expect `id` to be a string of 10 to 36 characters. When the
+    consumer runs this contract, the value `abcdefghij` will be returned by
+    the mock service.
+
+    """
+
+    def __init__(self, minimum, maximum, example=None):
+        """
+        Create a new StringLength.
+
+        :param minimum: The minimum number of characters, at least 0.
+        :type minimum: int
+        :param maximum: The maximum number of characters, at least `minimum`.
+        :type maximum: int
+        :param example: A value to be returned by the mock service, which
+            must be within the bounds. Defaults to a string of `x`
+            characters of the minimum length, or of one character if the
+            minimum is 0 and the maximum allows it.
+        :type example: basestring
+        :raises ValueError: If the bounds are invalid or the example is not a
+            string that fits them.
+        """
+        if not 0 <= minimum <= maximum:
+            raise ValueError(
+                'Expected 0 <= minimum <= maximum, got minimum={} and '
+                'maximum={}'.format(minimum, maximum))
+
+        if example is None:
+            example = 'x' * max(minimum, min(1, maximum))
+        elif not isinstance(example, six.string_types):
+            raise ValueError(
+                'Example {!r} must be a string'.format(example))
+        elif not minimum <= len(example) <= maximum:
+            raise ValueError(
+                'Example {!r} must be between {} and {} characters'.format(
+                    example, minimum, maximum))
+
+        super(StringLength, self).__init__(
+            r'\A[\s\S]{{{},{}}}\Z'.format(minimum, maximum), example)
+
+
+class OneOf(Term):
+    """
+    Expect a string which is one of a fixed set of values.
+
+    Example:
+    >>> from pact import Consumer, Provider
+    >>> pact = Consumer('consumer').has_pact_with(Provider('provider'))
+    >>> (pact.given('there is an order')
+    ...  .upon_receiving('a request for the order')
+    ...  .with_request('get', '/order')
+    ...  .will_respond_with(200, body={
+    ...    'status': OneOf(['pending', 'shipped', 'delivered'])
+    ...  }))
+
+    Would expect `status` to be exactly one of `pending`, `shipped` or
+    `delivered`. When the consumer runs this contract, the value `pending`
+    will be returned by the mock service.
+
+    """
+
+    def __init__(self, values):
+        """
+        Create a new OneOf.
+
+        :param values: The allowed values. The first one is returned by the
+            mock service.
+        :type values: list, tuple
+        :raises ValueError: If `values` is not a non-empty list or tuple of
+            strings.
+        """
+        if not isinstance(values, (list, tuple)):
+            raise ValueError(
+                'OneOf requires a list or tuple of values, got {!r}'.format(
+                    values))
+
+        if not values:
+            raise ValueError('OneOf requires at least one value')
+
+        if not all(isinstance(value, six.string_types) for value in values):
+            raise ValueError(
+                'OneOf values must all be strings, got {!r}'.format(values))
+
+        super(OneOf, self).__init__(
+            r'\A(' + '|'.join(re.escape(value) for value in values) + r')\Z',
+            values[0])
+
+
+def _ordered(values):
+    """
+    Order the converted items of a set so it serializes to a stable array.
+
+    Values are sorted directly when they are comparable. Otherwise numbers
+    come first, then strings, then any other values ordered by their
+    canonical JSON form, so the result never depends on memory layout.
+
+    :param values: The already converted items of a set.
+    :type values: iterable
+    :rtype: list
+    """
+    values = list(values)
+    try:
+        return sorted(values)
+    except TypeError:
+        return sorted(values, key=_ordering_key)
+
+
+def _ordering_key(value):
+    """Sort key used by `_ordered` when values are not comparable."""
+    if isinstance(value, (int, float)):
+        return (0, value, '')
+    if isinstance(value, six.string_types):
+        return (1, 0, value)
+    return (2, 0, json.dumps(value, sort_keys=True))
+
+
 def from_term(term):
     """
     Parse the provided term into the JSON for the mock service.
 
+    Sets are converted to lists, see `_ordered` for the resulting order.
+
     :param term: The term to be parsed.
-    :type term: None, list, dict, int, float, str, unicode, Matcher
+    :type term: None, list, set, dict, int, float, str, unicode, Matcher
     :return: The JSON representation for this term.
     :rtype: dict, list, str
     """
@@ -196,6 +330,8 @@ def from_term(term):
         return {k: from_term(v) for k, v in term.items()}
     elif isinstance(term, list):
         return [from_term(t) for i, t in enumerate(term)]
+    elif isinstance(term, (set, frozenset)):
+        return _ordered(from_term(t) for t in term)
     elif issubclass(term.__class__, (Matcher,)):
         return term.generate()
     else:
@@ -207,7 +343,7 @@ def get_generated_values(input):
     Resolve (nested) Matchers to their generated values for assertion.
 
     :param input: The input to be resolved to its generated values.
-    :type input: None, list, dict, int, float, bool, str, unicode, Matcher
+    :type input: None, list, set, dict, int, float, bool, str, unicode, Matcher
     :return: The input resolved to its generated value(s)
     :rtype: None, list, dict, int, float, bool, str, unicode, Matcher
     """
@@ -219,6 +355,8 @@ def get_generated_values(input):
         return {k: get_generated_values(v) for k, v in input.items()}
     if isinstance(input, list):
         return [get_generated_values(t) for i, t in enumerate(input)]
+    if isinstance(input, (set, frozenset)):
+        return _ordered(get_generated_values(t) for t in input)
     elif isinstance(input, Like):
         return get_generated_values(input.matcher)
     elif isinstance(input, EachLike):
@@ -260,10 +398,15 @@ class Format:
         self.ip_address = self.ip_address()
         self.hexadecimal = self.hexadecimal()
         self.ipv6_address = self.ipv6_address()
+        self.hostname = self.hostname()
         self.uuid = self.uuid()
         self.timestamp = self.timestamp()
         self.date = self.date()
         self.time = self.time()
+        self.duration = self.duration()
+        self.semver = self.semver()
+        self.base64 = self.base64()
+        self.base64_url = self.base64_url()
 
     def integer_or_identifier(self):
         """
@@ -310,6 +453,15 @@ class Format:
         """
         return Term(self.Regexes.ipv6_address.value, '::ffff:192.0.2.128')
 
+    def hostname(self):
+        """
+        Match any hostname.
+
+        :return: a Term object with a hostname regex.
+        :rtype: Term
+        """
+        return Term(self.Regexes.hostname.value, 'example.com')
+
     def uuid(self):
         """
         Match any uuid.
@@ -360,6 +512,42 @@ class Format:
             ).time().isoformat()
         )
 
+    def duration(self):
+        """
+        Match any ISO 8601 duration.
+
+        :return: a Term object with a duration regex.
+        :rtype: Term
+        """
+        return Term(self.Regexes.duration.value, 'P3Y6M')
+
+    def semver(self):
+        """
+        Match any semantic version.
+
+        :return: a Term object with a semantic version regex.
+        :rtype: Term
+        """
+        return Term(self.Regexes.semver.value, '1.0.0')
+
+    def base64(self):
+        """
+        Match any base64 encoded string.
+
+        :return: a Term object with a base64 regex.
+        :rtype: Term
+        """
+        return Term(self.Regexes.base64.value, 'cGFjdA==')
+
+    def base64_url(self):
+        """
+        Match any URL safe base64 encoded string.
+
+        :return: a Term object with a URL safe base64 regex.
+        :rtype: Term
+        """
+        return Term(self.Regexes.base64_url.value, 'cGFjdA')
+
     class Regexes(Enum):
         """Regex Enum for common formats."""
 
@@ -388,6 +576,9 @@ class Format:
             r'-1]?\d?\d)(\.(25[0-5]|2[0-4]\d|[0-1]?\d?\d)){3}\Z)|(\A:(:[' \
             r'0-9a-f]{1,4}){1,5}:(25[0-5]|2[0-4]\d|[0-1]?\d?\d)(\.(25[0-5]' \
             r'|2[0-4]\d|[0-1]?\d?\d)){3}\Z)'
+        hostname = r'^(?=.{1,253}$)' \
+            r'[A-Za-z0-9]([A-Za-z0-9-]{0,61}[A-Za-z0-9])?' \
+            r'(\.[A-Za-z0-9]([A-Za-z0-9-]{0,61}[A-Za-z0-9])?)*$'
         uuid = r'[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}'
         timestamp = r'^([\+-]?\d{4}(?!\d{2}\b))((-?)((0[1-9]|1[0-2])(\3(' \
             r'[12]\d|0[1-9]|3[01]))?|W([0-4]\d|5[0-2])(-?[1-7])?|(00[1-' \
@@ -398,3 +589,13 @@ class Format:
             r'0[1-9]|3[01]))?|W([0-4]\d|5[0-2])(-?[1-7])?|(00[1-9]|0[1-9]\d|' \
             r'[12]\d{2}|3([0-5]\d|6[1-6])))?)'
         time_regex = r'^(T\d\d:\d\d(:\d\d)?(\.\d+)?(([+-]\d\d:\d\d)|Z)?)?$'
+        duration = r'^P(?!$)(\d+Y)?(\d+M)?(\d+W)?(\d+D)?' \
+            r'(T(?=\d)(\d+H)?(\d+M)?(\d+(\.\d+)?S)?)?$'
+        semver = r'^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)' \
+            r'(-((0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)' \
+            r'(\.(0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?' \
+            r'(\+([0-9a-zA-Z-]+(\.[0-9a-zA-Z-]+)*))?$'
+        base64 = r'^([A-Za-z0-9+/]{4})*' \
+            r'([A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$'
+        base64_url = r'^([A-Za-z0-9_-]{4})*' \
+            r'([A-Za-z0-9_-]{2}(==)?|[A-Za-z0-9_-]{3}=?)?$'
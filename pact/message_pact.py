@@ -206,4 +206,5 @@ class MessagePact(Broker):
                 pact_dir=self.pact_dir,
                 tag_with_git_branch=self.consumer.tag_with_git_branch,
                 consumer_tags=self.consumer.tags,
+                branch=self.consumer.branch,
             )
@@ -238,7 +238,8 @@ class Pact(Broker):
                 self.consumer.version,
                 tag_with_git_branch=self.consumer.tag_with_git_branch,
                 consumer_tags=self.consumer.tags,
-                pact_dir=self.pact_dir
+                pact_dir=self.pact_dir,
+                branch=self.consumer.branch
             )
 
     def upon_receiving(self, scenario):
@@ -32,6 +32,13 @@ class Verifier(object):
             # do something
             raise Exception()
 
+        self.validate_branch(**kwargs)
+
+    def validate_branch(self, **kwargs):
+        """Validate a provider version branch has a version."""
+        if kwargs.get('provider_version_branch') and not kwargs.get('publish_version'):
+            raise ValueError('publish_version is required when provider_version_branch is set')
+
     def verify_pacts(self, *pacts, enable_pending=False, include_wip_pacts_since=None, **kwargs):
         """Verify our pacts from the provider.
 
@@ -71,8 +78,12 @@ class Verifier(object):
             enable_pending ([Boolean])
             include_wip_pacts_since ([String])
             publish_version ([String])
+            provider_version_branch ([String])
+            build_url ([String])
 
         """
+        self.validate_branch(**kwargs)
+
         broker_username = kwargs.get('broker_username', None)
         broker_password = kwargs.get('broker_password', None)
         broker_url = kwargs.get('broker_url', None)
@@ -101,9 +112,13 @@ class Verifier(object):
         timeout = kwargs.get('timeout', None)
         consumer_tags = kwargs.get('consumer_tags', [])
         provider_tags = kwargs.get('provider_tags', [])
+        provider_version_branch = kwargs.get('provider_version_branch')
         states_setup_url = kwargs.get('provider_states_setup_url', None)
         verbose = kwargs.get('verbose', False)
+        report_format = kwargs.get('report_format', None)
+        report_output = kwargs.get('report_output', None)
         provider_app_version = kwargs.get('publish_version', None)
+        build_url = kwargs.get('build_url', None)
         publish_verification_results = kwargs.get('publish_verification_results', None)
         raw_consumer_selectors = kwargs.get('consumer_version_selectors', [])
         consumer_selectors = self._build_consumer_selectors(raw_consumer_selectors)
@@ -116,9 +131,13 @@ class Verifier(object):
             'timeout': timeout,
             'consumer_tags': list(consumer_tags),
             'provider_tags': list(provider_tags),
+            'provider_version_branch': provider_version_branch,
             'provider_states_setup_url': states_setup_url,
             'verbose': verbose,
+            'report_format': report_format,
+            'report_output': report_output,
             'provider_app_version': provider_app_version,
+            'build_url': build_url,
             'consumer_selectors': consumer_selectors,
             'publish_verification_results': publish_verification_results
         }
@@ -9,6 +9,10 @@ import subprocess
 from os.path import isdir, join, isfile
 from os import listdir
 
+# Formatters supported by the --format option of the Ruby verifier
+REPORT_FORMATS = ('progress', 'documentation', 'json')
+
+
 def capture_logs(process, verbose):
     """Capture logs from ruby process."""
     result = ''
@@ -133,6 +137,26 @@ class VerifyWrapper(object):
         if len(pacts) == 0 and not self._broker_present(**kwargs):
             raise PactException('Pact urls or Pact broker required')
 
+        report_format = kwargs.get('report_format')
+        if report_format and report_format not in REPORT_FORMATS:
+            raise PactException(
+                'Unsupported report format: {}. Must be one of {}'.format(
+                    report_format, ', '.join(REPORT_FORMATS)))
+
+        report_output = kwargs.get('report_output')
+        if report_output:
+            if isdir(report_output):
+                raise PactException(
+                    'Report output path is a directory: {}'.format(report_output))
+
+            report_dir = os.path.dirname(os.path.abspath(report_output))
+            writable = isdir(report_dir) and os.access(report_dir, os.W_OK)
+            if isfile(report_output):
+                writable = writable and os.access(report_output, os.W_OK)
+            if not writable:
+                raise PactException(
+                    'Report output path is not writable: {}'.format(report_output))
+
     def call_verify(
             self, *pacts, provider_base_url, provider, enable_pending=False,
             include_wip_pacts_since=None, **kwargs
@@ -152,7 +176,11 @@ class VerifyWrapper(object):
             '--pact-broker-base-url': kwargs.get('broker_url', None),
             '--provider-states-setup-url': kwargs.get('provider_states_setup_url'),
             '--log-dir': kwargs.get('log_dir'),
-            '--log-level': kwargs.get('log_level')
+            '--log-level': kwargs.get('log_level'),
+            '--provider-version-branch': kwargs.get('provider_version_branch'),
+            '--build-url': kwargs.get('build_url'),
+            '--format': kwargs.get('report_format'),
+            '--out': kwargs.get('report_output')
         }
 
         command = [VERIFIER_PATH]
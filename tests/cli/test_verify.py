@@ -281,6 +281,7 @@ class mainTestCase(TestCase):
             '--consumer-version-selector={"tag": "staging", "latest": true}',
             '--provider-version-tag=dev',
             '--provider-version-tag=qa',
+            '--provider-version-branch=main',
             '--provider-base-url=http://localhost',
             '--provider=provider',
             '--provider-states-setup-url=http://localhost/provider-states/set',
@@ -289,6 +290,7 @@ class mainTestCase(TestCase):
             '--pact-broker-token=token',
             '--publish-verification-results',
             '--provider-app-version=1.2.3',
+            '--build-url=http://ci/builds/1',
             '--timeout=60',
             '--publish-verification-results',
             '--verbose',
@@ -309,14 +311,39 @@ class mainTestCase(TestCase):
                                 consumer_selectors=['{"tag": "master", "latest": true}',
                                                     '{"tag": "staging", "latest": true}'],
                                 provider_tags=['dev', 'qa'],
+                                provider_version_branch='main',
                                 provider_app_version='1.2.3',
                                 publish_verification_results=True,
+                                build_url='http://ci/builds/1',
                                 provider_states_setup_url='http://localhost/provider-states/set',
                                 timeout=60,
                                 verbose=True,
                                 enable_pending=True,
                                 include_wip_pacts_since='2018-01-01')
 
+    @patch("pact.verify_wrapper.VerifyWrapper.call_verify")
+    @patch('pact.cli.verify.path_exists', return_value=True)
+    def test_report_options(self, mock_path_exists, mock_wrapper):
+        mock_wrapper.return_value = 0, None
+        result = self.runner.invoke(verify.main, self.simple_pact_opts + [
+            '--format=json',
+            '--out=report.json',
+        ])
+
+        self.assertEqual(result.exit_code, 0, result.output)
+        self.assertEqual(mock_wrapper.call_args.kwargs['report_format'], 'json')
+        self.assertEqual(mock_wrapper.call_args.kwargs['report_output'], 'report.json')
+
+    @patch("pact.verify_wrapper.VerifyWrapper.call_verify")
+    @patch('pact.cli.verify.path_exists', return_value=True)
+    def test_unsupported_report_format(self, mock_path_exists, mock_wrapper):
+        result = self.runner.invoke(verify.main, self.simple_pact_opts + ['-f', 'junit'])
+
+        self.assertEqual(result.exit_code, 2)
+        self.assertIn('Invalid value', result.output)
+        self.assertIn('junit', result.output)
+        mock_wrapper.assert_not_called()
+
     @patch("pact.verify_wrapper.isfile", return_value=True)
     def test_publishing_missing_version(self, mock_isfile):
         result = self.runner.invoke(verify.main, [
@@ -328,6 +355,20 @@ class mainTestCase(TestCase):
         self.assertIn(
             'Provider application version is required', result.output)
 
+    @patch("pact.verify_wrapper.VerifyWrapper.call_verify")
+    def test_provider_version_branch_missing_version(self, mock_wrapper):
+        result = self.runner.invoke(verify.main, [
+            '--provider-base-url=http://localhost',
+            '--pact-broker-url=http://broker',
+            '--provider=provider_app',
+            '--provider-version-branch=main'
+        ])
+        self.assertEqual(result.exit_code, 1)
+        self.assertIn(
+            'Provider application version is required', result.output)
+        self.assertIn('--provider-version-branch', result.output)
+        mock_wrapper.assert_not_called()
+
     @patch('pact.cli.verify.path_exists', return_value=True)
     def test_file_does_not_exist_errors(self, mock_path_exists):
         mock_path_exists.return_value = False
@@ -19,6 +19,11 @@ class ConsumerTestCase(TestCase):
         self.assertEqual(result.name, 'TestConsumer')
         self.assertIs(result.service_cls, Pact)
 
+    def test_init_branch(self):
+        self.assertIsNone(Consumer('TestConsumer').branch)
+        result = Consumer('TestConsumer', branch='main')
+        self.assertEqual(result.branch, 'main')
+
     def test_has_pact_with(self):
         result = self.consumer.has_pact_with(self.provider)
         self.assertIs(result, self.mock_service.return_value)
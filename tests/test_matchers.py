@@ -2,8 +2,8 @@ import datetime
 
 from unittest import TestCase
 
-from pact.matchers import EachLike, Like, Matcher, SomethingLike, \
-    Term, Format, from_term, get_generated_values
+from pact.matchers import EachLike, Like, Matcher, OneOf, SomethingLike, \
+    StringLength, Term, Format, from_term, get_generated_values
 
 
 class MatcherTestCase(TestCase):
@@ -118,6 +118,95 @@ class TermTestCase(TestCase):
                  'generate': 'abcXYZ'}})
 
 
+class StringLengthTestCase(TestCase):
+    def test_regex(self):
+        self.assertEqual(StringLength(10, 36, 'abcdefghij').generate(), {
+            'json_class': 'Pact::Term',
+            'data': {
+                'generate': 'abcdefghij',
+                'matcher': {
+                    'json_class': 'Regexp',
+                    'o': 0,
+                    's': r'\A[\s\S]{10,36}\Z'}}})
+
+    def test_default_example(self):
+        self.assertEqual(get_generated_values(StringLength(3, 5)), 'xxx')
+        self.assertEqual(get_generated_values(StringLength(0, 5)), 'x')
+        self.assertEqual(get_generated_values(StringLength(0, 0)), '')
+
+    def test_default_example_fits_regex(self):
+        for minimum, maximum in ((3, 5), (0, 5), (0, 0), (7, 7)):
+            term = StringLength(minimum, maximum)
+            self.assertRegex(get_generated_values(term), term.matcher)
+
+    def test_bounds_apply_to_whole_string(self):
+        term = StringLength(1, 3)
+        self.assertRegex('a\nb', term.matcher)
+        self.assertNotRegex('ab\ncdefgh', term.matcher)
+        self.assertNotRegex('abcdefgh\nab', term.matcher)
+
+    def test_minimum_greater_than_maximum(self):
+        with self.assertRaises(ValueError):
+            StringLength(5, 3)
+
+    def test_negative_minimum(self):
+        with self.assertRaises(ValueError):
+            StringLength(-1, 3)
+
+    def test_example_not_a_string(self):
+        with self.assertRaises(ValueError):
+            StringLength(1, 5, 123)
+
+    def test_example_out_of_bounds(self):
+        with self.assertRaises(ValueError):
+            StringLength(3, 5, 'ab')
+        with self.assertRaises(ValueError):
+            StringLength(3, 5, 'abcdef')
+
+
+class OneOfTestCase(TestCase):
+    def test_regex(self):
+        self.assertEqual(OneOf(['pending', 'shipped']).generate(), {
+            'json_class': 'Pact::Term',
+            'data': {
+                'generate': 'pending',
+                'matcher': {
+                    'json_class': 'Regexp',
+                    'o': 0,
+                    's': r'\A(pending|shipped)\Z'}}})
+
+    def test_escapes_values(self):
+        term = OneOf(['a.b', 'c|d', '(e)'])
+        self.assertRegex('a.b', term.matcher)
+        self.assertRegex('c|d', term.matcher)
+        self.assertRegex('(e)', term.matcher)
+        self.assertNotRegex('axb', term.matcher)
+        self.assertNotRegex('c', term.matcher)
+
+    def test_example_is_first_value(self):
+        self.assertEqual(get_generated_values(OneOf(['b', 'a'])), 'b')
+
+    def test_empty_values(self):
+        with self.assertRaises(ValueError):
+            OneOf([])
+
+    def test_tuple_values(self):
+        self.assertEqual(get_generated_values(OneOf(('open', 'closed'))), 'open')
+
+    def test_matches_whole_string(self):
+        term = OneOf(['open', 'closed'])
+        self.assertNotRegex('open\nanything else', term.matcher)
+        self.assertNotRegex('reopen', term.matcher)
+
+    def test_non_string_values(self):
+        with self.assertRaises(ValueError):
+            OneOf([1, 2, 3])
+
+    def test_values_not_a_list(self):
+        with self.assertRaises(ValueError):
+            OneOf('open')
+
+
 class FromTermTestCase(TestCase):
     def test_dict(self):
         expected = {'administrator': False, 'id': 123, 'username': 'user'}
@@ -139,6 +228,21 @@ class FromTermTestCase(TestCase):
         term = [1, 123, 'sample']
         self.assertEqual(from_term(term), term)
 
+    def test_set(self):
+        self.assertEqual(from_term({3, 1, 2}), [1, 2, 3])
+
+    def test_frozenset(self):
+        self.assertEqual(from_term(frozenset(['b', 'a'])), ['a', 'b'])
+
+    def test_set_of_mixed_types(self):
+        self.assertEqual(from_term({10, 'x', 9}), [9, 10, 'x'])
+
+    def test_set_of_terms(self):
+        terms = {Term(value, value) for value in ('d', 'b', 'a', 'c')}
+        self.assertEqual(
+            [t['data']['generate'] for t in from_term(terms)],
+            ['a', 'b', 'c', 'd'])
+
     def test_each_like(self):
         self.assertEqual(
             from_term(EachLike({'a': 1})),
@@ -185,7 +289,7 @@ class FromTermTestCase(TestCase):
 
     def test_unknown_type(self):
         with self.assertRaises(ValueError):
-            from_term(set())
+            from_term(object())
 
 
 class GetGeneratedValuesTestCase(TestCase):
@@ -208,6 +312,13 @@ class GetGeneratedValuesTestCase(TestCase):
         term = [1, 123, 'sample']
         self.assertEqual(get_generated_values(term), term)
 
+    def test_set(self):
+        self.assertEqual(get_generated_values({'b', 'a'}), ['a', 'b'])
+
+    def test_set_of_terms(self):
+        terms = {Term(value, value) for value in ('d', 'b', 'a', 'c')}
+        self.assertEqual(get_generated_values(terms), ['a', 'b', 'c', 'd'])
+
     def test_dict(self):
         expected = {'administrator': False, 'id': 123, 'username': 'user'}
         self.assertEqual(get_generated_values(expected), expected)
@@ -239,7 +350,7 @@ class GetGeneratedValuesTestCase(TestCase):
 
     def test_unknown_type(self):
         with self.assertRaises(ValueError):
-            get_generated_values(set())
+            get_generated_values(object())
 
 
 class FormatTestCase(TestCase):
@@ -331,6 +442,30 @@ class FormatTestCase(TestCase):
             },
         )
 
+    def test_hostname(self):
+        hostname = self.formatter.hostname.generate()
+        self.assertEqual(
+            hostname,
+            {
+                "json_class": "Pact::Term",
+                "data": {
+                    "matcher": {
+                        "json_class": "Regexp",
+                        "s": self.formatter.Regexes.hostname.value,
+                        "o": 0,
+                    },
+                    "generate": "example.com",
+                },
+            },
+        )
+
+    def test_hostname_regex(self):
+        regex = self.formatter.Regexes.hostname.value
+        for value in ('localhost', 'example.com', 'api-1.eu.example.com'):
+            self.assertRegex(value, regex)
+        for value in ('-example.com', 'example-.com', 'exa mple.com', 'a..b', 'a' * 64):
+            self.assertNotRegex(value, regex)
+
     def test_uuid(self):
         uuid = self.formatter.uuid.generate()
         self.assertEqual(
@@ -404,3 +539,99 @@ class FormatTestCase(TestCase):
                 },
             },
         )
+
+    def test_duration(self):
+        duration = self.formatter.duration.generate()
+        self.assertEqual(
+            duration,
+            {
+                "json_class": "Pact::Term",
+                "data": {
+                    "matcher": {
+                        "json_class": "Regexp",
+                        "s": self.formatter.Regexes.duration.value,
+                        "o": 0,
+                    },
+                    "generate": "P3Y6M",
+                },
+            },
+        )
+
+    def test_duration_regex(self):
+        regex = self.formatter.Regexes.duration.value
+        for value in ('P3Y6M', 'P1W', 'PT0.5S', 'P1DT12H30M5S'):
+            self.assertRegex(value, regex)
+        for value in ('P', 'PT', '3Y', 'P1DT'):
+            self.assertNotRegex(value, regex)
+
+    def test_semver(self):
+        semver = self.formatter.semver.generate()
+        self.assertEqual(
+            semver,
+            {
+                "json_class": "Pact::Term",
+                "data": {
+                    "matcher": {
+                        "json_class": "Regexp",
+                        "s": self.formatter.Regexes.semver.value,
+                        "o": 0,
+                    },
+                    "generate": "1.0.0",
+                },
+            },
+        )
+
+    def test_semver_regex(self):
+        regex = self.formatter.Regexes.semver.value
+        for value in ('1.0.0', '0.10.2-rc.1', '2.0.0+build.5', '1.2.3-alpha-1+exp.sha'):
+            self.assertRegex(value, regex)
+        for value in ('1.0', '01.0.0', '1.0.0-', 'v1.0.0'):
+            self.assertNotRegex(value, regex)
+
+    def test_base64(self):
+        base64 = self.formatter.base64.generate()
+        self.assertEqual(
+            base64,
+            {
+                "json_class": "Pact::Term",
+                "data": {
+                    "matcher": {
+                        "json_class": "Regexp",
+                        "s": self.formatter.Regexes.base64.value,
+                        "o": 0,
+                    },
+                    "generate": "cGFjdA==",
+                },
+            },
+        )
+
+    def test_base64_regex(self):
+        regex = self.formatter.Regexes.base64.value
+        for value in ('cGFjdA==', 'cHl0aG9u', 'cGFjdHM=', '+/8='):
+            self.assertRegex(value, regex)
+        for value in ('cGFjdA', 'cGFjdA=', '-_8=', 'cGFj dA=='):
+            self.assertNotRegex(value, regex)
+
+    def test_base64_url(self):
+        base64_url = self.formatter.base64_url.generate()
+        self.assertEqual(
+            base64_url,
+            {
+                "json_class": "Pact::Term",
+                "data": {
+                    "matcher": {
+                        "json_class": "Regexp",
+                        "s": self.formatter.Regexes.base64_url.value,
+                        "o": 0,
+                    },
+                    "generate": "cGFjdA",
+                },
+            },
+        )
+
+    def test_base64_url_regex(self):
+        regex = self.formatter.Regexes.base64_url.value
+        for value in ('cGFjdA', 'cGFjdA==', 'cHl0aG9u', '-_8', '-_8='):
+            self.assertRegex(value, regex)
+        for value in ('+/8=', 'cGFjd', 'cGFjdA='):
+            self.assertNotRegex(value, regex)
@@ -19,6 +19,11 @@ class MessageConsumerTestCase(TestCase):
         self.assertEqual(result.name, 'TestMessageConsumer')
         self.assertIs(result.service_cls, MessagePact)
 
+    def test_init_branch(self):
+        self.assertIsNone(MessageConsumer('TestMessageConsumer').branch)
+        result = MessageConsumer('TestMessageConsumer', branch='main')
+        self.assertEqual(result.branch, 'main')
+
     def test_has_pact_with(self):
         result = self.message_consumer.has_pact_with(self.provider)
         self.assertIs(result, self.mock_service.return_value)
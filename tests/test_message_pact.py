@@ -149,6 +149,24 @@ class MessagePactContextManagerTestCase(MessagePactTestCase):
         self.write_to_pact_file.assert_called_once()
         self.mock_publish.assert_called_once()
 
+    def test_publishes_consumer_branch(self):
+        consumer = MessageConsumer('TestConsumer', version='1.0.0', branch='main')
+        pact = MessagePact(
+            consumer, self.provider, publish_to_broker=True, pact_dir='/pacts',
+            broker_base_url='http://localhost')
+
+        with pact:
+            pass
+
+        self.mock_publish.assert_called_once_with(
+            pact,
+            'TestConsumer',
+            '1.0.0',
+            pact_dir='/pacts',
+            tag_with_git_branch=False,
+            consumer_tags=None,
+            branch='main')
+
     def test_context_raises_error(self):
         pact = MessagePact(
             self.consumer, self.provider, publish_to_broker=True,
@@ -371,7 +371,8 @@ class PactStartShutdownServerTestCase(TestCase):
         ruby_exe = Mock(spec=Process)
         self.mock_Process.return_value.children.return_value = [ruby_exe]
         self.mock_Pid_exists.return_value = False
-        pact = Pact(Consumer('consumer', version='abc'), Provider('provider'), publish_to_broker=True, pact_dir='some_dir')
+        pact = Pact(Consumer('consumer', version='abc', branch='main'), Provider('provider'),
+                    publish_to_broker=True, pact_dir='some_dir')
         pact._process = Mock(spec=Popen, pid=999)
         pact.stop_service()
 
@@ -389,7 +390,8 @@ class PactStartShutdownServerTestCase(TestCase):
             'abc',
             consumer_tags=None,
             tag_with_git_branch=False,
-            pact_dir='some_dir')
+            pact_dir='some_dir',
+            branch='main')
 
     def test_stop_fails_posix(self):
         self.mock_platform.return_value = 'Linux'
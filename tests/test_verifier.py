@@ -130,6 +130,20 @@ class VerifierPactsTestCase(TestCase):
             mock_wrapper.call_args.kwargs,
         )
 
+    @patch("pact.verify_wrapper.VerifyWrapper.call_verify")
+    def test_provider_version_branch_requires_publish_version(self, mock_wrapper):
+        with self.assertRaises(ValueError):
+            self.verifier.verify_pacts('path/to/pact1', provider_version_branch='main')
+
+        mock_wrapper.assert_not_called()
+
+    @patch('pact.verify_wrapper.VerifyWrapper.call_verify', return_value=(0, None))
+    @patch('pact.verifier.path_exists', return_value=True)
+    def test_passes_report_options(self, mock_path_exists, mock_wrapper):
+        self.verifier.verify_pacts('any.json', report_format='json', report_output='report.json')
+        self.assertEqual(mock_wrapper.call_args.kwargs['report_format'], 'json')
+        self.assertEqual(mock_wrapper.call_args.kwargs['report_output'], 'report.json')
+
 class VerifierBrokerTestCase(TestCase):
 
     def setUp(self):
@@ -262,3 +276,27 @@ class VerifierBrokerTestCase(TestCase):
             ('include_wip_pacts_since', '2018-01-01') in mock_wrapper.call_args.kwargs.items(),
             mock_wrapper.call_args.kwargs,
         )
+
+    @patch('pact.verify_wrapper.VerifyWrapper.call_verify', return_value=(0, None))
+    def test_passes_provider_version_branch(self, mock_wrapper):
+        self.verifier.verify_with_broker(publish_version='1.0.0', provider_version_branch='main')
+        self.assertEqual(mock_wrapper.call_args.kwargs['provider_version_branch'], 'main')
+
+    @patch('pact.verify_wrapper.VerifyWrapper.call_verify', return_value=(0, None))
+    def test_provider_version_branch_requires_publish_version(self, mock_wrapper):
+        with self.assertRaises(ValueError) as context:
+            self.verifier.verify_with_broker(provider_version_branch='main')
+
+        self.assertIn('publish_version is required', str(context.exception))
+        mock_wrapper.assert_not_called()
+
+    @patch('pact.verify_wrapper.VerifyWrapper.call_verify', return_value=(0, None))
+    def test_publish_without_version_is_not_validated(self, mock_wrapper):
+        self.verifier.verify_with_broker(publish=True)
+
+        mock_wrapper.assert_called_once()
+
+    @patch('pact.verify_wrapper.VerifyWrapper.call_verify', return_value=(0, None))
+    def test_passes_build_url(self, mock_wrapper):
+        self.verifier.verify_with_broker(publish_version='1.0.0', build_url='http://ci/builds/1')
+        self.assertEqual(mock_wrapper.call_args.kwargs['build_url'], 'http://ci/builds/1')
@@ -1,4 +1,5 @@
 import os
+import tempfile
 from unittest import TestCase
 
 from mock import patch, Mock, call
@@ -53,7 +54,8 @@ class VerifyWrapperTestCase(TestCase):
             '--consumer-version-tag=prod',
             '--consumer-version-tag=dev',
             '--provider-version-tag=dev',
-            '--provider-version-tag=qa']
+            '--provider-version-tag=qa',
+            '--provider-version-branch=main']
 
     def assertProcess(self, *expected):
         self.assertEqual(self.mock_Popen.call_count, 1)
@@ -151,7 +153,8 @@ class VerifyWrapperTestCase(TestCase):
                                              broker_token='token',
                                              broker_url='http://broker',
                                              consumer_tags=['prod', 'dev'],
-                                             provider_tags=['dev', 'qa'])
+                                             provider_tags=['dev', 'qa'],
+                                             provider_version_branch='main')
 
         self.assertProcess(*self.broker_call)
         self.assertEqual(result, 0)
@@ -197,9 +200,11 @@ class VerifyWrapperTestCase(TestCase):
                                              provider='test_provider',
                                              provider_base_url='http://localhost',
                                              provider_app_version='1.2.3',
-                                             publish_verification_results=True)
+                                             publish_verification_results=True,
+                                             build_url='http://ci/builds/1')
 
-        self.default_call.extend(['--provider-app-version', '1.2.3', '--publish-verification-results'])
+        self.default_call.extend(['--provider-app-version', '1.2.3', '--publish-verification-results',
+                                  '--build-url=http://ci/builds/1'])
 
         self.assertProcess(*self.default_call)
         self.assertEqual(result, 0)
@@ -220,6 +225,75 @@ class VerifyWrapperTestCase(TestCase):
         mock_expand_dirs.assert_called_with(['path/to/pact1',
                                              'path/to/pact2'])
 
+    def test_report_options(self):
+        self.mock_Popen.return_value.returncode = 0
+        wrapper = VerifyWrapper()
+
+        result, output = wrapper.call_verify('./pacts/consumer-provider.json',
+                                             './pacts/consumer-provider2.json',
+                                             provider='test_provider',
+                                             provider_base_url='http://localhost',
+                                             report_format='json',
+                                             report_output='report.json')
+
+        self.default_call.extend(['--format=json', '--out=report.json'])
+
+        self.assertProcess(*self.default_call)
+        self.assertEqual(result, 0)
+
+    def test_report_output_must_be_writable(self):
+        wrapper = VerifyWrapper()
+
+        with self.assertRaises(PactException) as context:
+            wrapper.call_verify('./pacts/consumer-provider.json',
+                                provider='test_provider',
+                                provider_base_url='http://localhost',
+                                report_output='not/a/real/dir/report.json')
+
+        self.assertTrue('Report output path is not writable' in context.exception.message)
+        self.mock_Popen.assert_not_called()
+
+    def test_existing_report_output_must_be_writable(self):
+        wrapper = VerifyWrapper()
+        access = os.access
+
+        with tempfile.NamedTemporaryFile(suffix='.json') as report:
+            with patch('pact.verify_wrapper.os.access',
+                       side_effect=lambda path, mode: path != report.name and access(path, mode)):
+                with self.assertRaises(PactException) as context:
+                    wrapper.call_verify('./pacts/consumer-provider.json',
+                                        provider='test_provider',
+                                        provider_base_url='http://localhost',
+                                        report_output=report.name)
+
+        self.assertTrue('Report output path is not writable' in context.exception.message)
+        self.mock_Popen.assert_not_called()
+
+    def test_report_output_must_not_be_a_directory(self):
+        wrapper = VerifyWrapper()
+
+        with tempfile.TemporaryDirectory() as report_dir:
+            with self.assertRaises(PactException) as context:
+                wrapper.call_verify('./pacts/consumer-provider.json',
+                                    provider='test_provider',
+                                    provider_base_url='http://localhost',
+                                    report_output=report_dir)
+
+        self.assertTrue('Report output path is a directory' in context.exception.message)
+        self.mock_Popen.assert_not_called()
+
+    def test_unsupported_report_format(self):
+        wrapper = VerifyWrapper()
+
+        with self.assertRaises(PactException) as context:
+            wrapper.call_verify('./pacts/consumer-provider.json',
+                                provider='test_provider',
+                                provider_base_url='http://localhost',
+                                report_format='junit')
+
+        self.assertTrue('Unsupported report format: junit' in context.exception.message)
+        self.mock_Popen.assert_not_called()
+
 
 class path_existsTestCase(TestCase):
     def test_path_exists(self):